@@ -16,6 +16,132 @@ pub struct Bitstream<'a> {
     remaining: u8,
 }
 
+/// Error produced by the fallible reading methods when the bitstream is consumed past its end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitstreamError {
+    /// The stream ran out of bytes while more were required to satisfy the read.
+    UnexpectedEof,
+}
+
+impl std::fmt::Display for BitstreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitstreamError::UnexpectedEof => {
+                f.write_str("unexpected end of bitstream while reading")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BitstreamError {}
+
+/// A streaming [`Bitstream`] that pulls 16-bit words from an inner `R: io::Read` on demand
+/// instead of borrowing the whole compressed input up front.
+///
+/// Following Claxon's buffered-reader design, it keeps a single word of lookahead so that
+/// [`peek_bits`](Self::peek_bits) can see past the current word; words are refilled from the
+/// reader as they are exhausted, and [`BitstreamError::UnexpectedEof`] is returned once the
+/// reader is drained. This lets arbitrarily large LZXD streams be decoded without holding every
+/// 32 KB chunk in memory at once.
+pub struct BitstreamReader<R> {
+    inner: R,
+    // Current number in the bitstream.
+    n: u16,
+    // How many bits left in the current `n`.
+    remaining: u8,
+    // One word of lookahead, filled by `peek_bits` and consumed by the next refill.
+    next: Option<u16>,
+}
+
+impl<R: std::io::Read> BitstreamReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            n: 0,
+            remaining: 0,
+            next: None,
+        }
+    }
+
+    // Pull the next raw 16-bit word straight from the reader.
+    fn read_word(&mut self) -> Result<u16, BitstreamError> {
+        let mut buf = [0u8; 2];
+        self.inner
+            .read_exact(&mut buf)
+            .map_err(|_| BitstreamError::UnexpectedEof)?;
+        Ok(u16::from_le_bytes(buf))
+    }
+
+    // Ensure the lookahead word is populated and return it.
+    fn peek_word(&mut self) -> Result<u16, BitstreamError> {
+        if self.next.is_none() {
+            self.next = Some(self.read_word()?);
+        }
+        Ok(self.next.unwrap())
+    }
+
+    // Advance to the next 16-bit integer, reusing the lookahead word if one was buffered.
+    fn advance_buffer(&mut self) -> Result<(), BitstreamError> {
+        self.n = match self.next.take() {
+            Some(word) => word,
+            None => self.read_word()?,
+        };
+        self.remaining = 16;
+        Ok(())
+    }
+
+    pub fn read_bit(&mut self) -> Result<u16, BitstreamError> {
+        if self.remaining == 0 {
+            self.advance_buffer()?;
+        }
+
+        self.remaining -= 1;
+        self.n = self.n.rotate_left(1);
+        Ok(self.n & 1)
+    }
+
+    pub fn read_bits(&mut self, bits: u8) -> Result<u16, BitstreamError> {
+        assert!(bits <= 16);
+        debug_assert!(self.remaining <= 16);
+
+        if bits <= self.remaining {
+            self.remaining -= bits;
+            self.n = self.n.rotate_left(bits as u32);
+            Ok(self.n & ((1 << bits) - 1))
+        } else {
+            let hi = self.n.rotate_left(self.remaining as u32) & ((1 << self.remaining) - 1);
+            let bits = bits - self.remaining;
+            self.advance_buffer()?;
+
+            self.remaining -= bits;
+            self.n = self.n.rotate_left(bits as u32);
+            // `bits` may be 16 which would overflow the left shift, operate on `u32` and trunc.
+            let lo = self.n & ((1u32 << bits) as u16).wrapping_sub(1);
+
+            Ok(((hi as u32) << bits) as u16 | lo)
+        }
+    }
+
+    pub fn peek_bits(&mut self, bits: u8) -> Result<u16, BitstreamError> {
+        assert!(bits <= 16);
+
+        if bits <= self.remaining {
+            Ok(self.n.rotate_left(bits as u32) & ((1 << bits) - 1))
+        } else {
+            let hi = self.n.rotate_left(self.remaining as u32) & ((1 << self.remaining) - 1);
+            let bits = bits - self.remaining;
+
+            // Buffer one extra word ahead so lookahead past the current word works. As with the
+            // slice variant, we may peek more than we need at the end of a chunk; pretend there
+            // are just zeros once the reader is drained.
+            let n = self.peek_word().unwrap_or(0);
+            let lo = n.rotate_left(bits as u32) & ((1u32 << bits) as u16).wrapping_sub(1);
+
+            Ok(((hi as u32) << bits) as u16 | lo)
+        }
+    }
+}
+
 impl<'a> Bitstream<'a> {
     pub fn new(buffer: &'a [u8]) -> Self {
         Self {
@@ -33,36 +159,60 @@ impl<'a> Bitstream<'a> {
         self.buffer = &self.buffer[2..];
     }
 
+    // Fallible counterpart of `advance_buffer`: checks there is a whole word left before
+    // refilling `n`, returning an error instead of indexing out of bounds.
+    #[inline(always)]
+    fn try_advance_buffer(&mut self) -> Result<(), BitstreamError> {
+        if self.buffer.len() < 2 {
+            return Err(BitstreamError::UnexpectedEof);
+        }
+        self.advance_buffer();
+        Ok(())
+    }
+
     pub fn read_bit(&mut self) -> u16 {
+        self.try_read_bit().unwrap()
+    }
+
+    pub fn read_bits(&mut self, bits: u8) -> u16 {
+        self.try_read_bits(bits).unwrap()
+    }
+
+    /// Fallible counterpart of [`read_bit`](Self::read_bit): returns
+    /// [`BitstreamError::UnexpectedEof`] if the stream is exhausted.
+    pub fn try_read_bit(&mut self) -> Result<u16, BitstreamError> {
         if self.remaining == 0 {
-            self.advance_buffer();
+            self.try_advance_buffer()?;
         }
 
         self.remaining -= 1;
         self.n = self.n.rotate_left(1);
-        self.n & 1
+        Ok(self.n & 1)
     }
 
-    pub fn read_bits(&mut self, bits: u8) -> u16 {
+    /// Fallible counterpart of [`read_bits`](Self::read_bits): checks the buffer before each
+    /// refill and returns [`BitstreamError::UnexpectedEof`] on a truncated or corrupt stream
+    /// instead of panicking.
+    pub fn try_read_bits(&mut self, bits: u8) -> Result<u16, BitstreamError> {
         assert!(bits <= 16);
         debug_assert!(self.remaining <= 16);
 
         if bits <= self.remaining {
             self.remaining -= bits;
             self.n = self.n.rotate_left(bits as u32);
-            self.n & ((1 << bits) - 1)
+            Ok(self.n & ((1 << bits) - 1))
         } else {
             // No need to store `rol` result in `n` as we're about to overwrite it.
             let hi = self.n.rotate_left(self.remaining as u32) & ((1 << self.remaining) - 1);
             let bits = bits - self.remaining;
-            self.advance_buffer();
+            self.try_advance_buffer()?;
 
             self.remaining -= bits;
             self.n = self.n.rotate_left(bits as u32);
             // `bits` may be 16 which would overflow the left shift, operate on `u32` and trunc.
             let lo = self.n & ((1u32 << bits) as u16).wrapping_sub(1);
 
-            ((hi as u32) << bits) as u16 | lo
+            Ok(((hi as u32) << bits) as u16 | lo)
         }
     }
 
@@ -201,6 +351,62 @@ mod tests {
         assert!(bitstream.is_empty());
     }
 
+    #[test]
+    fn try_read_past_end() {
+        let bytes = [0xab, 0xcd];
+        let mut bitstream = Bitstream::new(&bytes);
+
+        // The single word can be consumed without error.
+        assert!(bitstream.try_read_bits(16).is_ok());
+        // Reading even a single further bit must report end-of-stream rather than panic.
+        assert_eq!(bitstream.try_read_bit(), Err(BitstreamError::UnexpectedEof));
+        assert_eq!(
+            bitstream.try_read_bits(4),
+            Err(BitstreamError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn reader_matches_slice() {
+        // The same bytes decoded through the slice and the streaming variant must agree.
+        let ns = [0b0_1_10_11_100_101_110_1u16, 0b11_1000_1001_1010_00u16];
+        let bit_lengths = [1u8, 1, 2, 2, 3, 3, 3, 3, 4, 4, 4];
+
+        let mut bytes = Vec::with_capacity(ns.len() * 2);
+        ns.iter().for_each(|n| bytes.extend(&n.to_le_bytes()));
+
+        let mut slice = Bitstream::new(&bytes);
+        let mut reader = BitstreamReader::new(std::io::Cursor::new(bytes.clone()));
+        for &bits in &bit_lengths {
+            assert_eq!(reader.read_bits(bits).unwrap(), slice.read_bits(bits));
+        }
+    }
+
+    #[test]
+    fn reader_reports_end_of_stream() {
+        let bytes = [0xab, 0xcd];
+        let mut reader = BitstreamReader::new(std::io::Cursor::new(bytes.to_vec()));
+        assert!(reader.read_bits(16).is_ok());
+        assert_eq!(reader.read_bit(), Err(BitstreamError::UnexpectedEof));
+    }
+
+    #[test]
+    fn reader_peeks_past_current_word() {
+        // Peeking across the word boundary must buffer the next word from the reader.
+        let ns = [0b00000000000_10001u16, 0b10000000001_00000u16];
+        let mut bytes = Vec::with_capacity(ns.len() * 2);
+        ns.iter().for_each(|n| bytes.extend(&n.to_le_bytes()));
+
+        let mut slice = Bitstream::new(&bytes);
+        let mut reader = BitstreamReader::new(std::io::Cursor::new(bytes.clone()));
+        assert_eq!(reader.read_bits(11).unwrap(), 0);
+        slice.read_bits(11);
+        // 16-bit peek now straddles the first and second words.
+        let expected = slice.peek_bits(16);
+        assert_eq!(reader.peek_bits(16).unwrap(), expected);
+        assert_eq!(reader.read_bits(16).unwrap(), expected);
+    }
+
     #[test]
     fn check_read_bit() {
         let bytes = [0b0110_1001, 0b1001_0110];
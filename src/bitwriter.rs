@@ -0,0 +1,118 @@
+/// The inverse of [`Bitstream`](crate::bitstream::Bitstream): accumulates bits most-significant
+/// first into a register and flushes completed 16-bit words into an owned, auto-growing buffer,
+/// laid out so that feeding the output back into `Bitstream::read_bits` round-trips.
+///
+/// Words are emitted in the same byte-swapped little-endian form the decoder expects (the low
+/// byte of the logical word first), so the first bit written is the first bit read back.
+pub struct BitWriter {
+    buffer: Vec<u8>,
+    // Pending bits, right-aligned in the register (the oldest bit sits at position `nbits - 1`).
+    n: u32,
+    // How many valid bits are currently held in `n`.
+    nbits: u8,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            n: 0,
+            nbits: 0,
+        }
+    }
+
+    /// Writes the low `bits` bits of `value`, most-significant first.
+    pub fn write_bits(&mut self, value: u16, bits: u8) {
+        assert!(bits <= 16);
+        let masked = if bits == 16 {
+            value as u32
+        } else {
+            value as u32 & ((1u32 << bits) - 1)
+        };
+
+        self.n = (self.n << bits) | masked;
+        self.nbits += bits;
+
+        while self.nbits >= 16 {
+            self.nbits -= 16;
+            let word = (self.n >> self.nbits) as u16;
+            self.buffer.extend_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    /// Writes a 16-bit value such that [`Bitstream::read_u16_le`](crate::bitstream::Bitstream::read_u16_le)
+    /// reads it back unchanged.
+    pub fn write_u16_le(&mut self, value: u16) {
+        self.write_bits(value.swap_bytes(), 16);
+    }
+
+    /// Writes a 24-bit value big-endian, the inverse of
+    /// [`Bitstream::read_u24_be`](crate::bitstream::Bitstream::read_u24_be).
+    pub fn write_u24_be(&mut self, value: u32) {
+        self.write_bits((value >> 8) as u16, 16);
+        self.write_bits((value & 0xff) as u16, 8);
+    }
+
+    /// Pads the final partial word with up to 15 zero bits to realign on the 16-bit boundary,
+    /// exactly as the format spec (and `Bitstream::is_empty`) expects, and returns the buffer.
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.nbits > 0 {
+            let pad = 16 - self.nbits;
+            self.write_bits(0, pad);
+        }
+        debug_assert_eq!(self.nbits, 0);
+        self.buffer
+    }
+}
+
+impl Default for BitWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitstream::Bitstream;
+
+    #[test]
+    fn round_trips_read_bits() {
+        let values = [(0u16, 1u8), (1, 1), (2, 2), (3, 2), (5, 3), (13, 4), (42, 6)];
+
+        let mut writer = BitWriter::new();
+        for &(value, bits) in &values {
+            writer.write_bits(value, bits);
+        }
+        let bytes = writer.finish();
+
+        let mut bitstream = Bitstream::new(&bytes);
+        for &(value, bits) in &values {
+            assert_eq!(bitstream.read_bits(bits), value);
+        }
+    }
+
+    #[test]
+    fn round_trips_u16_le_and_u24_be() {
+        let mut writer = BitWriter::new();
+        writer.write_u16_le(0xabcd);
+        writer.write_u24_be(0x123456);
+        let bytes = writer.finish();
+
+        let mut bitstream = Bitstream::new(&bytes);
+        assert_eq!(bitstream.read_u16_le(), 0xabcd);
+        assert_eq!(bitstream.read_u24_be(), 0x123456);
+    }
+
+    #[test]
+    fn finish_pads_to_word_boundary() {
+        let mut writer = BitWriter::new();
+        writer.write_bits(0b101, 3);
+        let bytes = writer.finish();
+
+        // A single 3-bit write realigns to a whole 16-bit word (two bytes).
+        assert_eq!(bytes.len(), 2);
+        let bitstream = Bitstream::new(&bytes);
+        assert!(!bitstream.is_empty());
+    }
+}
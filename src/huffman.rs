@@ -0,0 +1,271 @@
+use crate::bitstream::Bitstream;
+
+/// Number of bits resolved by the primary lookup table. LZXD codes are at most 16 bits long,
+/// so anything longer than `ROOT_BITS` is resolved through a secondary subtable.
+const ROOT_BITS: u8 = 9;
+
+/// A primary-table slot: either a leaf holding a decoded symbol and its code length, or a
+/// pointer into `subtables` together with the number of extra bits that subtable consumes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Entry {
+    /// Fully resolved symbol and its full code length `len` (`len <= ROOT_BITS` in the primary
+    /// table; subtable leaves hold longer codes).
+    Leaf { symbol: u16, len: u8 },
+    /// The code is longer than `ROOT_BITS`; index `subtables[offset..]` with the next
+    /// `sub_bits` bits to finish resolving it.
+    Link { offset: u32, sub_bits: u8 },
+    /// No code maps to this prefix.
+    Absent,
+}
+
+/// Error returned when a set of code lengths cannot form a valid canonical Huffman code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HuffmanError {
+    /// The code lengths describe more codes than the tree can hold (over-subscribed).
+    OverSubscribed,
+    /// The code lengths leave prefixes unassigned (incomplete), and the tree is not the
+    /// single-symbol degenerate case.
+    Incomplete,
+    /// A code length exceeds the 16-bit maximum LZXD permits.
+    LengthTooLong,
+}
+
+/// A table-driven canonical Huffman decoder layered on [`Bitstream::peek_bits`].
+///
+/// Construction assigns canonical codes by increasing length then symbol index, then fills a
+/// `2^ROOT_BITS` primary table; symbols longer than `ROOT_BITS` spill into secondary subtables
+/// indexed by the remaining bits. Decoding costs one memory load for short codes and two for
+/// long ones, replacing per-bit tree traversal.
+#[derive(Debug, PartialEq)]
+pub struct HuffmanTable {
+    primary: Vec<Entry>,
+    subtables: Vec<Entry>,
+}
+
+impl HuffmanTable {
+    /// Builds a decoder from a slice of per-symbol code lengths, where `lengths[s]` is the code
+    /// length for symbol `s` and a length of `0` means the symbol is absent.
+    ///
+    /// Returns [`HuffmanError`] for over-subscribed or incomplete code sets. The degenerate
+    /// trees (a single code of length `1`, or no code at all) are accepted; decoding them always
+    /// returns that symbol (symbol `0` when there is no code) and consumes one bit.
+    pub fn new(lengths: &[u8]) -> Result<Self, HuffmanError> {
+        let max_len = lengths.iter().copied().max().unwrap_or(0);
+
+        // LZXD codes are at most 16 bits; anything longer would index past the fixed-size
+        // counting arrays below, so reject it as malformed rather than panicking.
+        if max_len > 16 {
+            return Err(HuffmanError::LengthTooLong);
+        }
+
+        // Count how many symbols use each length, then derive the first canonical code per
+        // length (codes assigned by increasing length then symbol index).
+        let mut len_counts = [0u32; 17];
+        for &len in lengths {
+            len_counts[len as usize] += 1;
+        }
+
+        // Degenerate tree: no code at all, or a single code of length 1. Decode it by consuming
+        // one bit and always returning that symbol. A lone longer code is left to the check
+        // below, which rejects it as incomplete.
+        let present = lengths.iter().filter(|&&l| l != 0).count();
+        if present == 0 || (present == 1 && max_len == 1) {
+            let symbol = lengths.iter().position(|&l| l != 0).unwrap_or(0) as u16;
+            let len = lengths.get(symbol as usize).copied().unwrap_or(0).max(1);
+            let primary = vec![Entry::Leaf { symbol, len }; 1 << ROOT_BITS];
+            return Ok(Self {
+                primary,
+                subtables: Vec::new(),
+            });
+        }
+
+        // Kraft check: verify the code set is neither over- nor under-subscribed.
+        let mut left: i64 = 1;
+        for &count in &len_counts[1..=max_len as usize] {
+            left <<= 1;
+            left -= count as i64;
+            if left < 0 {
+                return Err(HuffmanError::OverSubscribed);
+            }
+        }
+        if left != 0 {
+            return Err(HuffmanError::Incomplete);
+        }
+
+        // `bl_count[0]` must be zero when deriving canonical start codes (RFC 1951); absent
+        // symbols are counted above but must not shift the length-1 start code off zero.
+        len_counts[0] = 0;
+        let mut next_code = [0u32; 17];
+        let mut code = 0u32;
+        for len in 1..=max_len as usize {
+            code = (code + len_counts[len - 1]) << 1;
+            next_code[len] = code;
+        }
+
+        let mut primary = vec![Entry::Absent; 1 << ROOT_BITS];
+        let mut subtables: Vec<Entry> = Vec::new();
+
+        for (symbol, &len) in lengths.iter().enumerate() {
+            if len == 0 {
+                continue;
+            }
+            let symbol = symbol as u16;
+            let code = next_code[len as usize];
+            next_code[len as usize] += 1;
+
+            if len <= ROOT_BITS {
+                // Fill every primary slot whose high `len` bits equal this code.
+                let shift = ROOT_BITS - len;
+                let base = (code << shift) as usize;
+                for slot in 0..(1usize << shift) {
+                    primary[base + slot] = Entry::Leaf { symbol, len };
+                }
+            } else {
+                // Split the code into the `ROOT_BITS` primary prefix and the remaining bits.
+                let prefix = (code >> (len - ROOT_BITS)) as usize;
+                let sub_bits = max_len - ROOT_BITS;
+                let offset = match primary[prefix] {
+                    Entry::Link { offset, .. } => offset,
+                    _ => {
+                        let offset = subtables.len() as u32;
+                        subtables.resize(subtables.len() + (1 << sub_bits), Entry::Absent);
+                        primary[prefix] = Entry::Link { offset, sub_bits };
+                        offset
+                    }
+                };
+                let low_len = len - ROOT_BITS;
+                let shift = sub_bits - low_len;
+                let low = (code & ((1 << low_len) - 1)) as usize;
+                let base = offset as usize + (low << shift);
+                for slot in 0..(1usize << shift) {
+                    subtables[base + slot] = Entry::Leaf { symbol, len };
+                }
+            }
+        }
+
+        Ok(Self { primary, subtables })
+    }
+
+    /// Decodes the next symbol from `bitstream`, consuming exactly the matched code's length.
+    pub fn decode(&self, bitstream: &mut Bitstream) -> u16 {
+        let index = bitstream.peek_bits(ROOT_BITS) as usize;
+        match self.primary[index] {
+            Entry::Leaf { symbol, len } => {
+                bitstream.read_bits(len);
+                symbol
+            }
+            Entry::Link { offset, sub_bits } => {
+                // Consume the primary bits, then resolve the rest from the subtable.
+                bitstream.read_bits(ROOT_BITS);
+                let low = bitstream.peek_bits(sub_bits) as usize;
+                match self.subtables[offset as usize + low] {
+                    Entry::Leaf { symbol, len } => {
+                        bitstream.read_bits(len - ROOT_BITS);
+                        symbol
+                    }
+                    _ => unreachable!("complete code set leaves no gaps in the subtable"),
+                }
+            }
+            Entry::Absent => unreachable!("complete code set leaves no gaps in the primary table"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits_from(words: &[u16]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(words.len() * 2);
+        words.iter().for_each(|n| bytes.extend(&n.to_le_bytes()));
+        bytes
+    }
+
+    #[test]
+    fn decode_short_codes() {
+        // Symbols 0..=3 with lengths 1, 2, 3, 3 -> codes 0, 10, 110, 111.
+        let table = HuffmanTable::new(&[1, 2, 3, 3]).unwrap();
+
+        // Stream: 0 10 110 111, packed MSB-first into a 16-bit word.
+        let bytes = bits_from(&[0b0_10_110_111_0000000u16]);
+        let mut bitstream = Bitstream::new(&bytes);
+
+        assert_eq!(table.decode(&mut bitstream), 0);
+        assert_eq!(table.decode(&mut bitstream), 1);
+        assert_eq!(table.decode(&mut bitstream), 2);
+        assert_eq!(table.decode(&mut bitstream), 3);
+    }
+
+    #[test]
+    fn decode_with_absent_symbols() {
+        // Symbol 0 is absent; symbols 1 and 2 share length 1 -> codes 0 and 1. This is the
+        // common LZXD shape (many zero lengths) and exercises the `bl_count[0] = 0` fix.
+        let table = HuffmanTable::new(&[0, 1, 1]).unwrap();
+
+        // Stream: 0 1 0 1, packed MSB-first into a 16-bit word.
+        let bytes = bits_from(&[0b0101_000000000000u16]);
+        let mut bitstream = Bitstream::new(&bytes);
+
+        assert_eq!(table.decode(&mut bitstream), 1);
+        assert_eq!(table.decode(&mut bitstream), 2);
+        assert_eq!(table.decode(&mut bitstream), 1);
+        assert_eq!(table.decode(&mut bitstream), 2);
+    }
+
+    #[test]
+    fn single_symbol_tree() {
+        let table = HuffmanTable::new(&[0, 1, 0]).unwrap();
+        let bytes = bits_from(&[0u16]);
+        let mut bitstream = Bitstream::new(&bytes);
+        assert_eq!(table.decode(&mut bitstream), 1);
+    }
+
+    #[test]
+    fn decode_long_codes_through_subtable() {
+        use crate::bitwriter::BitWriter;
+
+        // A complete code set reaching 11-bit codes, exercising the `Entry::Link` subtable path
+        // for every code longer than ROOT_BITS (9 bits). Canonical code for symbol `s` of length
+        // `len` is `2^len - 2`, with the final same-length symbol taking `2^len - 1`.
+        let lengths = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 11];
+        let codes = [0u16, 2, 6, 14, 30, 62, 126, 254, 510, 1022, 2046, 2047];
+        let table = HuffmanTable::new(&lengths).unwrap();
+
+        // Encode each symbol's code MSB-first, twice over, so correct in-order decoding proves
+        // each code consumed exactly `len` bits.
+        let mut writer = BitWriter::new();
+        for _ in 0..2 {
+            for (&code, &len) in codes.iter().zip(lengths.iter()) {
+                writer.write_bits(code, len);
+            }
+        }
+        let bytes = writer.finish();
+        let mut bitstream = Bitstream::new(&bytes);
+
+        for _ in 0..2 {
+            for symbol in 0..lengths.len() as u16 {
+                assert_eq!(table.decode(&mut bitstream), symbol);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_overlong_lengths() {
+        // A 17-bit length exceeds LZXD's maximum and must be a recoverable error, not a panic.
+        assert_eq!(
+            HuffmanTable::new(&[1, 2, 17]),
+            Err(HuffmanError::LengthTooLong)
+        );
+    }
+
+    #[test]
+    fn rejects_bad_code_sets() {
+        // Two length-1 codes plus a length-2 code over-subscribes the tree.
+        assert_eq!(
+            HuffmanTable::new(&[1, 1, 2]),
+            Err(HuffmanError::OverSubscribed)
+        );
+        // Three length-2 codes leave one prefix unassigned, so the tree is incomplete.
+        assert_eq!(HuffmanTable::new(&[2, 2, 2]), Err(HuffmanError::Incomplete));
+    }
+}